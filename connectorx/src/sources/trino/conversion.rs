@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+use super::errors::TrinoSourceError;
+use super::typesystem::TrinoTypeSystem;
+
+/// A user-supplied override for how a single column should be interpreted,
+/// for tables (often backed by text-based connectors) where the declared
+/// Trino type doesn't match what the caller actually wants - e.g. a VARCHAR
+/// column that is really a timestamp. Parsed the same way as Vector's
+/// `Conversion`: a bare keyword, or `keyword|<chrono format>` for the two
+/// timestamp variants.
+#[derive(Clone, Debug)]
+pub enum TrinoConversion {
+    Boolean,
+    Integer,
+    Float,
+    Timestamp(String),
+    TimestampTz(String),
+}
+
+impl TrinoConversion {
+    pub(crate) fn type_system(&self) -> TrinoTypeSystem {
+        match self {
+            TrinoConversion::Boolean => TrinoTypeSystem::Boolean(true),
+            TrinoConversion::Integer => TrinoTypeSystem::BigInt(true),
+            TrinoConversion::Float => TrinoTypeSystem::Double(true),
+            TrinoConversion::Timestamp(_) => TrinoTypeSystem::Timestamp(true),
+            TrinoConversion::TimestampTz(_) => TrinoTypeSystem::TimestampTz(true),
+        }
+    }
+}
+
+impl FromStr for TrinoConversion {
+    type Err = TrinoSourceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '|');
+        let kind = parts.next().unwrap_or("");
+        let fmt = parts.next();
+
+        Ok(match kind {
+            "boolean" => TrinoConversion::Boolean,
+            "integer" => TrinoConversion::Integer,
+            "float" => TrinoConversion::Float,
+            "timestamp" => {
+                TrinoConversion::Timestamp(fmt.unwrap_or("%Y-%m-%d %H:%M:%S%.f").to_string())
+            }
+            "timestamp_tz" => {
+                TrinoConversion::TimestampTz(fmt.unwrap_or("%Y-%m-%d %H:%M:%S%.f %:z").to_string())
+            }
+            _ => return Err(anyhow!("unknown Trino conversion: {}", s).into()),
+        })
+    }
+}
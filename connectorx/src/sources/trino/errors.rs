@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+use crate::errors::ConnectorXError;
+
+#[derive(Error, Debug)]
+pub enum TrinoSourceError {
+    #[error(transparent)]
+    ConnectorXError(#[from] ConnectorXError),
+
+    #[error(transparent)]
+    PrustoError(#[from] prusto::error::Error),
+
+    #[error(transparent)]
+    UrlParseError(#[from] url::ParseError),
+
+    #[error(transparent)]
+    Utf8Error(#[from] std::str::Utf8Error),
+
+    /// Any other errors that are too trivial to be put here explicitly.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
@@ -1,10 +1,14 @@
-use std::{marker::PhantomData, sync::Arc};
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
 
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use chrono_tz::Tz;
 use fehler::{throw, throws};
-use prusto::{auth::Auth, Client, ClientBuilder, DataSet, Presto, Row};
+use prusto::{auth::Auth, Client, ClientBuilder, DataSet, Presto, PrestoTy, Row};
+use rust_decimal::Decimal;
 use serde_json::Value;
+use sqlparser::ast::{BinaryOperator, Expr, SetExpr, Statement};
 use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
 use std::convert::TryFrom;
 use tokio::runtime::Runtime;
 
@@ -15,21 +19,254 @@ use crate::{
     sql::{limit1_query, CXQuery},
 };
 
-pub use self::{errors::TrinoSourceError, typesystem::TrinoTypeSystem};
+pub use self::{
+    conversion::TrinoConversion,
+    errors::TrinoSourceError,
+    typesystem::{TrinoTimeTz, TrinoTypeSystem},
+};
 use urlencoding::decode;
 
 use super::{PartitionParser, Source, SourcePartition};
 
 use anyhow::anyhow;
 
+pub mod conversion;
 pub mod errors;
 pub mod typesystem;
 
+// Count rows with `SELECT COUNT(*) FROM (<query>) cxtmp` instead of pulling
+// the whole result set just to call `.len()` on it - the caller still needs
+// to run the query itself afterwards to fetch the actual rows, so this
+// avoided a full redundant transfer per partition.
 #[throws(TrinoSourceError)]
 fn get_total_rows(rt: Arc<Runtime>, client: Arc<Client>, query: &CXQuery<String>) -> usize {
-    rt.block_on(client.get_all::<Row>(query.to_string()))
-        .map_err(TrinoSourceError::PrustoError)?
-        .len()
+    let dialect = GenericDialect {};
+    let stmt = Parser::parse_sql(&dialect, &query.to_string())
+        .map_err(|e| anyhow!("Trino cannot parse query for row count: {}", e))?
+        .pop()
+        .ok_or_else(|| anyhow!("Trino query for row count is empty"))?;
+    let count_query = format!("SELECT COUNT(*) AS cxcnt FROM ({}) cxtmp", stmt);
+
+    let dataset: DataSet<Row> = rt
+        .block_on(client.get_all::<Row>(count_query))
+        .map_err(TrinoSourceError::PrustoError)?;
+    let row = dataset
+        .into_vec()
+        .pop()
+        .ok_or_else(|| anyhow!("Trino row count query returned no rows"))?;
+
+    row.value()[0]
+        .as_u64()
+        .ok_or_else(|| anyhow!("Trino row count is not a number"))? as usize
+}
+
+// MIN/MAX of the partition column. Kept on the integer path when both
+// bounds fit a JSON number as an integer (BIGINT/INTEGER/...), since
+// routing a large BIGINT through f64 loses precision above 2^53 and can
+// shift the interpolated partition boundaries enough to skip or double-count
+// rows near them. Falls back to f64 for columns with fractional values.
+enum ColRange {
+    Int(i64, i64),
+    Float(f64, f64),
+}
+
+impl ColRange {
+    // Evenly spaced boundaries for partition `i` of `num`, formatted as the
+    // SQL literal `inject_partition_predicate` should splice in.
+    fn bounds(&self, i: usize, num: usize) -> (String, String) {
+        match *self {
+            ColRange::Int(min, max) => {
+                let min = min as i128;
+                let max = max as i128;
+                let span = max - min;
+                let lo = min + span * (i as i128) / (num as i128);
+                let hi = min + span * ((i + 1) as i128) / (num as i128);
+                (lo.to_string(), hi.to_string())
+            }
+            ColRange::Float(min, max) => {
+                let lo = min + (max - min) * (i as f64) / (num as f64);
+                let hi = min + (max - min) * ((i + 1) as f64) / (num as f64);
+                (lo.to_string(), hi.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod col_range_tests {
+    use super::*;
+
+    // Every partition's upper bound must be the next partition's lower
+    // bound, and the partitions together must cover exactly [min, max] -
+    // this is where an off-by-one in the interpolation would either skip
+    // or double-count rows near a boundary.
+    #[test]
+    fn int_partitions_are_contiguous_and_cover_the_full_range() {
+        let range = ColRange::Int(0, 97);
+        let num = 5;
+
+        let bounds: Vec<(i128, i128)> = (0..num)
+            .map(|i| {
+                let (lo, hi) = range.bounds(i, num);
+                (lo.parse().unwrap(), hi.parse().unwrap())
+            })
+            .collect();
+
+        assert_eq!(bounds[0].0, 0, "first partition's lo must be the column min");
+        assert_eq!(
+            bounds[num - 1].1,
+            97,
+            "last partition's hi must be the column max"
+        );
+        for w in bounds.windows(2) {
+            assert_eq!(
+                w[0].1, w[1].0,
+                "partition hi must equal the next partition's lo"
+            );
+        }
+    }
+
+    #[test]
+    fn float_partitions_are_contiguous_and_cover_the_full_range() {
+        let range = ColRange::Float(-2.5, 2.5);
+        let num = 4;
+
+        let bounds: Vec<(f64, f64)> = (0..num)
+            .map(|i| {
+                let (lo, hi) = range.bounds(i, num);
+                (lo.parse().unwrap(), hi.parse().unwrap())
+            })
+            .collect();
+
+        assert_eq!(bounds[0].0, -2.5);
+        assert_eq!(bounds[num - 1].1, 2.5);
+        for w in bounds.windows(2) {
+            assert_eq!(w[0].1, w[1].0);
+        }
+    }
+
+    // A MIN == MAX column (e.g. a single distinct value) degenerates every
+    // partition to the same point rather than panicking or dividing unevenly.
+    #[test]
+    fn degenerate_min_equals_max_collapses_every_partition_to_one_point() {
+        let range = ColRange::Int(42, 42);
+        let num = 3;
+
+        for i in 0..num {
+            let (lo, hi) = range.bounds(i, num);
+            assert_eq!(lo, "42");
+            assert_eq!(hi, "42");
+        }
+    }
+}
+
+#[throws(TrinoSourceError)]
+fn get_col_range(
+    rt: &Runtime,
+    client: &Client,
+    origin_query: &str,
+    partition_col: &str,
+) -> ColRange {
+    let range_query = format!(
+        "SELECT MIN({col}) AS cxmin, MAX({col}) AS cxmax FROM ({origin}) cxtmp",
+        col = partition_col,
+        origin = origin_query
+    );
+
+    let dataset: DataSet<Row> = rt
+        .block_on(client.get_all::<Row>(range_query))
+        .map_err(TrinoSourceError::PrustoError)?;
+
+    let row = dataset.into_vec().pop().ok_or_else(|| {
+        anyhow!(
+            "Trino range query for column {} returned no rows",
+            partition_col
+        )
+    })?;
+    let value = row.value();
+
+    match (value[0].as_i64(), value[1].as_i64()) {
+        (Some(min), Some(max)) => ColRange::Int(min, max),
+        _ => {
+            let min = value[0].as_f64().ok_or_else(|| {
+                anyhow!("Trino partition column {} is not numeric", partition_col)
+            })?;
+            let max = value[1].as_f64().ok_or_else(|| {
+                anyhow!("Trino partition column {} is not numeric", partition_col)
+            })?;
+            ColRange::Float(min, max)
+        }
+    }
+}
+
+// Rewrite `origin_query` into `SELECT * FROM (origin_query) cxtmp WHERE col >= lo AND col < hi`
+// (or `<= hi` for the last partition, so the upper bound is inclusive), using sqlparser so the
+// predicate is appended safely regardless of how the origin query is shaped.
+//
+// Note this drops rows where `col` is NULL: every `>=`/`<`/`<=` comparison against NULL is
+// unknown rather than true, so a NULL-valued partition column is excluded from all partitions.
+// This matches the range-partitioning behavior of connector-x's other sources and is why range
+// partitioning should only be used on a column declared (or otherwise known to be) NOT NULL.
+#[throws(TrinoSourceError)]
+fn inject_partition_predicate(
+    origin_query: &str,
+    partition_col: &str,
+    lo: &str,
+    hi: &str,
+    is_last: bool,
+) -> CXQuery<String> {
+    let dialect = GenericDialect {};
+    let wrapped = format!("SELECT * FROM ({}) cxtmp", origin_query);
+    let mut statements = Parser::parse_sql(&dialect, &wrapped)
+        .map_err(|e| anyhow!("Trino cannot parse origin query for partitioning: {}", e))?;
+    let stmt = statements
+        .pop()
+        .ok_or_else(|| anyhow!("Trino origin query is empty"))?;
+
+    let predicate_sql = format!(
+        "SELECT * FROM cxtmp WHERE {col} >= {lo} AND {col} {op} {hi}",
+        col = partition_col,
+        lo = lo,
+        op = if is_last { "<=" } else { "<" },
+        hi = hi
+    );
+    let predicate_expr = match Parser::parse_sql(&dialect, &predicate_sql)
+        .map_err(|e| anyhow!("Trino cannot build partition predicate: {}", e))?
+        .pop()
+        .ok_or_else(|| anyhow!("Trino cannot build partition predicate"))?
+    {
+        Statement::Query(q) => match *q.body {
+            SetExpr::Select(select) => select
+                .selection
+                .ok_or_else(|| anyhow!("Trino cannot build partition predicate"))?,
+            _ => throw!(anyhow!("Trino cannot build partition predicate")),
+        },
+        _ => throw!(anyhow!("Trino cannot build partition predicate")),
+    };
+
+    let mut stmt = stmt;
+    match &mut stmt {
+        Statement::Query(q) => match q.body.as_mut() {
+            SetExpr::Select(select) => {
+                select.selection = Some(match select.selection.take() {
+                    Some(existing) => Expr::BinaryOp {
+                        left: Box::new(existing),
+                        op: BinaryOperator::And,
+                        right: Box::new(predicate_expr),
+                    },
+                    None => predicate_expr,
+                });
+            }
+            _ => throw!(anyhow!(
+                "Trino origin query must be a simple SELECT to be partitioned"
+            )),
+        },
+        _ => throw!(anyhow!(
+            "Trino origin query must be a SELECT statement to be partitioned"
+        )),
+    }
+
+    CXQuery::Naked(stmt.to_string())
 }
 
 pub struct TrinoSource {
@@ -39,6 +276,13 @@ pub struct TrinoSource {
     queries: Vec<CXQuery<String>>,
     names: Vec<String>,
     schema: Vec<TrinoTypeSystem>,
+    partition_col: Option<String>,
+    partition_num: Option<usize>,
+    conversions: HashMap<String, TrinoConversion>,
+    // DECIMAL(p, s) columns, keyed by name: `TrinoTypeSystem::Decimal` can only
+    // carry the nullability flag (see its doc comment), so the declared
+    // precision/scale populated by `fetch_metadata` live here instead.
+    decimal_precision: HashMap<String, (usize, usize)>,
 }
 
 impl TrinoSource {
@@ -69,8 +313,33 @@ impl TrinoSource {
             queries: vec![],
             names: vec![],
             schema: vec![],
+            partition_col: None,
+            partition_num: None,
+            conversions: HashMap::new(),
+            decimal_precision: HashMap::new(),
         }
     }
+
+    pub fn set_partition_range_column(&mut self, col: &str) {
+        self.partition_col = Some(col.into());
+    }
+
+    pub fn set_partition_num(&mut self, num: usize) {
+        self.partition_num = Some(num);
+    }
+
+    #[throws(TrinoSourceError)]
+    pub fn set_conversion(&mut self, column: &str, conversion: &str) {
+        self.conversions
+            .insert(column.to_string(), conversion.parse()?);
+    }
+
+    // (precision, scale) for every DECIMAL column seen by `fetch_metadata`,
+    // keyed by column name - exposed for sizing a fixed-point column, since
+    // `TrinoTypeSystem::Decimal` itself can only carry nullability.
+    pub fn decimal_precision(&self) -> &HashMap<String, (usize, usize)> {
+        &self.decimal_precision
+    }
 }
 
 impl Source for TrinoSource
@@ -113,8 +382,16 @@ where
         let schema = dataset.split().0;
 
         for (name, t) in schema {
+            if let PrestoTy::Decimal(precision, scale) = &t {
+                self.decimal_precision
+                    .insert(name.clone(), (*precision as usize, *scale as usize));
+            }
+            let resolved = match self.conversions.get(&name) {
+                Some(conversion) => conversion.type_system(),
+                None => TrinoTypeSystem::try_from(t.clone())?,
+            };
             self.names.push(name.clone());
-            self.schema.push(TrinoTypeSystem::try_from(t.clone())?);
+            self.schema.push(resolved);
         }
     }
 
@@ -142,12 +419,38 @@ where
     fn partition(self) -> Vec<Self::Partition> {
         let mut ret = vec![];
 
-        for query in self.queries {
+        let queries = match (&self.origin_query, &self.partition_col, self.partition_num) {
+            (Some(origin_query), Some(partition_col), Some(num)) if num > 1 => {
+                let range = get_col_range(&self.rt, &self.client, origin_query, partition_col)?;
+                (0..num)
+                    .map(|i| {
+                        let (lo, hi) = range.bounds(i, num);
+                        inject_partition_predicate(
+                            origin_query,
+                            partition_col,
+                            &lo,
+                            &hi,
+                            i == num - 1,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            _ => self.queries,
+        };
+
+        let conversions: Vec<Option<TrinoConversion>> = self
+            .names
+            .iter()
+            .map(|name| self.conversions.get(name).cloned())
+            .collect();
+
+        for query in queries {
             ret.push(TrinoSourcePartition::new(
                 self.client.clone(),
                 query,
                 self.schema.clone(),
                 self.rt.clone(),
+                conversions.clone(),
             )?);
         }
         ret
@@ -160,6 +463,7 @@ pub struct TrinoSourcePartition {
     schema: Vec<TrinoTypeSystem>,
     rt: Arc<Runtime>,
     nrows: usize,
+    conversions: Vec<Option<TrinoConversion>>,
 }
 
 impl TrinoSourcePartition {
@@ -169,6 +473,7 @@ impl TrinoSourcePartition {
         query: CXQuery<String>,
         schema: Vec<TrinoTypeSystem>,
         rt: Arc<Runtime>,
+        conversions: Vec<Option<TrinoConversion>>,
     ) -> Self {
         Self {
             client,
@@ -176,6 +481,7 @@ impl TrinoSourcePartition {
             schema: schema.to_vec(),
             rt,
             nrows: 0,
+            conversions,
         }
     }
 }
@@ -197,6 +503,7 @@ impl SourcePartition for TrinoSourcePartition {
             self.client.clone(),
             self.query.clone(),
             &self.schema,
+            self.conversions.clone(),
         )?
     }
 
@@ -210,10 +517,17 @@ impl SourcePartition for TrinoSourcePartition {
 }
 
 pub struct TrinoSourcePartitionParser<'a> {
+    rt: Arc<Runtime>,
+    client: Arc<Client>,
+    next_uri: Option<String>,
     rows: Vec<Row>,
     ncols: usize,
     current_col: usize,
     current_row: usize,
+    // Per-column conversion overrides, indexed by column position; only the
+    // timestamp variants need this at produce time since their custom
+    // `chrono` format string can't be recovered from the value alone.
+    conversions: Vec<Option<TrinoConversion>>,
     _phantom: &'a PhantomData<DataSet<Row>>,
 }
 
@@ -224,16 +538,23 @@ impl<'a> TrinoSourcePartitionParser<'a> {
         client: Arc<Client>,
         query: CXQuery,
         schema: &[TrinoTypeSystem],
+        conversions: Vec<Option<TrinoConversion>>,
     ) -> Self {
-        let rows = client.get_all::<Row>(query.to_string());
-        let data = rt.block_on(rows).map_err(TrinoSourceError::PrustoError)?;
-        let rows = data.clone().into_vec();
+        let data: DataSet<Row> = rt
+            .block_on(client.get::<Row>(query.to_string()))
+            .map_err(TrinoSourceError::PrustoError)?;
+        let next_uri = data.next_uri().map(|uri| uri.to_owned());
+        let rows = data.into_vec();
 
         Self {
+            rt,
+            client,
+            next_uri,
             rows,
             ncols: schema.len(),
             current_row: 0,
             current_col: 0,
+            conversions,
             _phantom: &PhantomData,
         }
     }
@@ -255,8 +576,137 @@ impl<'a> PartitionParser<'a> for TrinoSourcePartitionParser<'a> {
     fn fetch_next(&mut self) -> (usize, bool) {
         assert!(self.current_col == 0);
 
-        // results are always fetched in a single batch for Prusto
-        (self.rows.len(), true)
+        let rt = self.rt.clone();
+        let client = self.client.clone();
+        step_page(
+            &mut self.rows,
+            &mut self.current_row,
+            &mut self.next_uri,
+            |uri| {
+                let data: DataSet<Row> = rt
+                    .block_on(client.get_next::<Row>(uri))
+                    .map_err(TrinoSourceError::PrustoError)?;
+                Ok((data.into_vec(), data.next_uri().map(|uri| uri.to_owned())))
+            },
+        )?
+    }
+}
+
+// If the current page is exhausted and another might exist, pull pages via
+// `fetch_page` until one comes back non-empty or pages run out - Trino can
+// legitimately hand back an empty page while the query is still running, so
+// an empty page doesn't by itself mean the result is complete. Returns how
+// many rows are now available and whether this is the final batch.
+//
+// Generic over the row type and the page-fetch closure so this bookkeeping -
+// where a stray off-by-one would silently drop or duplicate rows - is
+// testable without a live Trino client; see the tests below.
+#[throws(TrinoSourceError)]
+fn step_page<T>(
+    rows: &mut Vec<T>,
+    current_row: &mut usize,
+    next_uri: &mut Option<String>,
+    mut fetch_page: impl FnMut(&str) -> Result<(Vec<T>, Option<String>), TrinoSourceError>,
+) -> (usize, bool) {
+    if *current_row == rows.len() && next_uri.is_some() {
+        *rows = vec![];
+        while rows.is_empty() {
+            let uri = match next_uri.take() {
+                Some(uri) => uri,
+                None => break,
+            };
+            let (page_rows, page_next_uri) = fetch_page(&uri)?;
+            *next_uri = page_next_uri;
+            *rows = page_rows;
+        }
+        *current_row = 0;
+    }
+    (rows.len(), next_uri.is_none())
+}
+
+#[cfg(test)]
+mod page_stepping_tests {
+    use super::*;
+
+    // Drains `step_page` end-to-end against a canned sequence of pages,
+    // including an empty intermediate page, and checks the rows come back
+    // in order with no drops or duplicates.
+    #[test]
+    fn reassembles_multi_page_result_in_order() {
+        let mut remaining_pages: std::collections::VecDeque<(Vec<i32>, Option<String>)> =
+            vec![
+                (vec![], Some("p3".to_string())),
+                (vec![20, 21, 22], None),
+            ]
+            .into_iter()
+            .collect();
+
+        let mut rows: Vec<i32> = vec![10, 11];
+        let mut current_row = 0;
+        let mut next_uri = Some("p2".to_string());
+        let mut seen = vec![];
+
+        loop {
+            let (len, is_last) = step_page(&mut rows, &mut current_row, &mut next_uri, |_uri| {
+                Ok(remaining_pages
+                    .pop_front()
+                    .expect("fetch_page called more times than there are pages"))
+            })
+            .expect("step_page should not fail for this canned sequence");
+
+            seen.extend_from_slice(&rows[current_row..len]);
+            current_row = len;
+
+            if is_last && current_row == rows.len() {
+                break;
+            }
+        }
+
+        assert_eq!(seen, vec![10, 11, 20, 21, 22]);
+        assert!(remaining_pages.is_empty());
+    }
+
+    // A page that comes back empty while the query is still running must
+    // not be mistaken for the end of the result: `step_page` skips past it
+    // and fetches the next page.
+    #[test]
+    fn empty_page_with_more_to_come_is_not_final() {
+        let mut rows: Vec<i32> = vec![];
+        let mut current_row = 0;
+        let mut next_uri = Some("p1".to_string());
+        let mut fetched = vec![];
+
+        let (len, is_last) = step_page(&mut rows, &mut current_row, &mut next_uri, |uri| {
+            fetched.push(uri.to_string());
+            match uri {
+                "p1" => Ok((vec![], Some("p2".to_string()))),
+                "p2" => Ok((vec![7], Some("p3".to_string()))),
+                _ => panic!("unexpected page {}", uri),
+            }
+        })
+        .unwrap();
+
+        assert_eq!(fetched, vec!["p1".to_string(), "p2".to_string()]);
+        assert_eq!(len, 1);
+        assert!(!is_last, "an empty page with a next_uri must not be final");
+        assert_eq!(next_uri, Some("p3".to_string()));
+    }
+
+    // Once `next_uri` is exhausted, `step_page` reports the final batch
+    // without attempting another fetch.
+    #[test]
+    fn no_next_uri_is_final_without_fetching() {
+        let mut rows: Vec<i32> = vec![1, 2, 3];
+        let mut current_row = 3;
+        let mut next_uri = None;
+
+        let (len, is_last) = step_page(&mut rows, &mut current_row, &mut next_uri, |_uri| {
+            panic!("fetch_page should not be called when next_uri is None")
+        })
+        .unwrap();
+
+        assert_eq!(len, 3);
+        assert!(is_last);
     }
 }
 
@@ -279,6 +729,10 @@ macro_rules! impl_produce_int {
                                 throw!(anyhow!("Trino cannot parse Number at position: ({}, {})", ridx, cidx))
                             }
                         }
+                        // Covers both a VARCHAR column coerced to this type by a
+                        // `TrinoConversion::Integer` override and a native integer type
+                        // that happened to arrive as a JSON string.
+                        Value::String(x) => x.parse().map_err(|_| anyhow!("Trino cannot parse String {:?} as integer at position: ({}, {})", x, ridx, cidx))?,
                         _ => throw!(anyhow!("Trino cannot parse Number at position: ({}, {})", ridx, cidx))
                     }
                 }
@@ -301,6 +755,7 @@ macro_rules! impl_produce_int {
                                 throw!(anyhow!("Trino cannot parse Number at position: ({}, {})", ridx, cidx))
                             }
                         }
+                        Value::String(x) => Some(x.parse().map_err(|_| anyhow!("Trino cannot parse String {:?} as integer at position: ({}, {})", x, ridx, cidx))?),
                         _ => throw!(anyhow!("Trino cannot parse Number at position: ({}, {})", ridx, cidx))
                     }
                 }
@@ -328,6 +783,10 @@ macro_rules! impl_produce_float {
                                 throw!(anyhow!("Trino cannot parse Number at position: ({}, {})", ridx, cidx))
                             }
                         }
+                        // Covers both a VARCHAR column coerced to this type by a
+                        // `TrinoConversion::Float` override and a native float type
+                        // that happened to arrive as a JSON string.
+                        Value::String(x) => x.parse().map_err(|_| anyhow!("Trino cannot parse String {:?} as float at position: ({}, {})", x, ridx, cidx))?,
                         _ => throw!(anyhow!("Trino cannot parse Number at position: ({}, {})", ridx, cidx))
                     }
                 }
@@ -350,6 +809,7 @@ macro_rules! impl_produce_float {
                                 throw!(anyhow!("Trino cannot parse Number at position: ({}, {})", ridx, cidx))
                             }
                         }
+                        Value::String(x) => Some(x.parse().map_err(|_| anyhow!("Trino cannot parse String {:?} as float at position: ({}, {})", x, ridx, cidx))?),
                         _ => throw!(anyhow!("Trino cannot parse Number at position: ({}, {})", ridx, cidx))
                     }
                 }
@@ -410,8 +870,15 @@ macro_rules! impl_produce_timestamp {
                     let (ridx, cidx) = self.next_loc()?;
                     let value = &self.rows[ridx].value()[cidx];
 
+                    // A `TrinoConversion::Timestamp` override carries its own `chrono` format
+                    // string (for VARCHAR columns that don't use Trino's native rendering).
+                    let fmt = match self.conversions.get(cidx).and_then(Option::as_ref) {
+                        Some(TrinoConversion::Timestamp(fmt)) => fmt.as_str(),
+                        _ => "%Y-%m-%d %H:%M:%S%.f",
+                    };
+
                     match value {
-                        Value::String(x) => NaiveDateTime::parse_from_str(x, "%Y-%m-%d %H:%M:%S%.f").map_err(|_| anyhow!("Trino cannot parse String at position: ({}, {}): {:?}", ridx, cidx, value))?,
+                        Value::String(x) => NaiveDateTime::parse_from_str(x, fmt).map_err(|_| anyhow!("Trino cannot parse String at position: ({}, {}): {:?}", ridx, cidx, value))?,
                         _ => throw!(anyhow!("Trino unknown value at position: ({}, {}): {:?}", ridx, cidx, value))
                     }
                 }
@@ -425,9 +892,14 @@ macro_rules! impl_produce_timestamp {
                     let (ridx, cidx) = self.next_loc()?;
                     let value = &self.rows[ridx].value()[cidx];
 
+                    let fmt = match self.conversions.get(cidx).and_then(Option::as_ref) {
+                        Some(TrinoConversion::Timestamp(fmt)) => fmt.as_str(),
+                        _ => "%Y-%m-%d %H:%M:%S%.f",
+                    };
+
                     match value {
                         Value::Null => None,
-                        Value::String(x) => Some(NaiveDateTime::parse_from_str(x, "%Y-%m-%d %H:%M:%S%.f").map_err(|_| anyhow!("Trino cannot parse String at position: ({}, {}): {:?}", ridx, cidx, value))?),
+                        Value::String(x) => Some(NaiveDateTime::parse_from_str(x, fmt).map_err(|_| anyhow!("Trino cannot parse String at position: ({}, {}): {:?}", ridx, cidx, value))?),
                         _ => throw!(anyhow!("Trino unknown value at position: ({}, {}): {:?}", ridx, cidx, value))
                     }
                 }
@@ -449,6 +921,10 @@ macro_rules! impl_produce_bool {
 
                     match value {
                         Value::Bool(x) => *x,
+                        // Covers both a VARCHAR column coerced to this type by a
+                        // `TrinoConversion::Boolean` override and a native boolean type
+                        // that happened to arrive as a JSON string.
+                        Value::String(x) => x.parse().map_err(|_| anyhow!("Trino cannot parse String {:?} as boolean at position: ({}, {})", x, ridx, cidx))?,
                         _ => throw!(anyhow!("Trino unknown value at position: ({}, {}): {:?}", ridx, cidx, value))
                     }
                 }
@@ -465,6 +941,7 @@ macro_rules! impl_produce_bool {
                     match value {
                         Value::Null => None,
                         Value::Bool(x) => Some(*x),
+                        Value::String(x) => Some(x.parse().map_err(|_| anyhow!("Trino cannot parse String {:?} as boolean at position: ({}, {})", x, ridx, cidx))?),
                         _ => throw!(anyhow!("Trino unknown value at position: ({}, {}): {:?}", ridx, cidx, value))
                     }
                 }
@@ -590,3 +1067,300 @@ impl<'r, 'a> Produce<'r, Option<NaiveDate>> for TrinoSourcePartitionParser<'a> {
         }
     }
 }
+
+// Trino renders a zoned timestamp either with a numeric offset
+// (`2020-01-01 00:00:00.000 +01:00`) or, more commonly, with the IANA zone
+// name it was stored with (`2020-01-01 00:00:00.000 America/New_York`). Try
+// the offset form first since it is unambiguous, then fall back to resolving
+// the trailing zone name through `chrono-tz`.
+#[throws(TrinoSourceError)]
+fn parse_trino_timestamp_tz(s: &str, fmt: Option<&str>) -> DateTime<FixedOffset> {
+    if let Some(fmt) = fmt {
+        DateTime::parse_from_str(s, fmt).map_err(|_| {
+            anyhow!(
+                "Trino cannot parse timestamp with time zone {:?} with format {:?}",
+                s,
+                fmt
+            )
+        })?
+    } else if let Ok(dt) = DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f %:z") {
+        dt
+    } else {
+        let (naive_part, zone_name) = s
+            .rsplit_once(' ')
+            .ok_or_else(|| anyhow!("Trino cannot parse timestamp with time zone: {}", s))?;
+        let naive = NaiveDateTime::parse_from_str(naive_part, "%Y-%m-%d %H:%M:%S%.f")
+            .map_err(|_| anyhow!("Trino cannot parse timestamp with time zone: {}", s))?;
+        let tz: Tz = zone_name
+            .parse()
+            .map_err(|_| anyhow!("Trino cannot resolve time zone: {}", zone_name))?;
+        let localized = tz
+            .from_local_datetime(&naive)
+            .single()
+            .ok_or_else(|| anyhow!("Trino ambiguous or invalid local datetime: {}", s))?;
+        localized.fixed_offset()
+    }
+}
+
+impl<'r, 'a> Produce<'r, DateTime<FixedOffset>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> DateTime<FixedOffset> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+        let fmt = match self.conversions.get(cidx).and_then(Option::as_ref) {
+            Some(TrinoConversion::TimestampTz(fmt)) => Some(fmt.as_str()),
+            _ => None,
+        };
+
+        match value {
+            Value::String(x) => parse_trino_timestamp_tz(x, fmt)?,
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<DateTime<FixedOffset>>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Option<DateTime<FixedOffset>> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+        let fmt = match self.conversions.get(cidx).and_then(Option::as_ref) {
+            Some(TrinoConversion::TimestampTz(fmt)) => Some(fmt.as_str()),
+            _ => None,
+        };
+
+        match value {
+            Value::Null => None,
+            Value::String(x) => Some(parse_trino_timestamp_tz(x, fmt)?),
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+// TIME WITH TIME ZONE carries no date, so it's synthesized onto the Unix
+// epoch day to reuse `parse_trino_timestamp_tz`'s offset/zone-name parsing;
+// the resulting offset is the real one Trino reported, not normalized away.
+#[throws(TrinoSourceError)]
+fn parse_trino_time_tz(s: &str) -> TrinoTimeTz {
+    let synthetic = format!("1970-01-01 {}", s);
+    TrinoTimeTz(parse_trino_timestamp_tz(&synthetic, None)?)
+}
+
+impl<'r, 'a> Produce<'r, TrinoTimeTz> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> TrinoTimeTz {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::String(x) => parse_trino_time_tz(x)?,
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<TrinoTimeTz>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Option<TrinoTimeTz> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::Null => None,
+            Value::String(x) => Some(parse_trino_time_tz(x)?),
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+// Trino sends DECIMAL columns back as JSON strings specifically so clients
+// don't round-trip them through f64 and lose precision, so parse the string
+// straight into a `rust_decimal::Decimal` rather than going through Number.
+impl<'r, 'a> Produce<'r, Decimal> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Decimal {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::String(x) => Decimal::from_str_exact(x).map_err(|_| {
+                anyhow!(
+                    "Trino cannot parse Decimal at position: ({}, {}): {:?}",
+                    ridx,
+                    cidx,
+                    value
+                )
+            })?,
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+// ARRAY(t) values are left as nested `serde_json::Value`s rather than
+// recursively dispatched through `Produce`, since the element type can
+// itself be another ARRAY/MAP/ROW.
+impl<'r, 'a> Produce<'r, Vec<Value>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Vec<Value> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::Array(x) => x.clone(),
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<Vec<Value>>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Option<Vec<Value>> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::Null => None,
+            Value::Array(x) => Some(x.clone()),
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+// MAP(k, v) comes back as a JSON object; ROW(...) comes back as a JSON
+// array with no field names attached, so its "key" is the field's position.
+// Both are surfaced uniformly as key/value pairs of raw JSON values.
+impl<'r, 'a> Produce<'r, Vec<(Value, Value)>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Vec<(Value, Value)> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::Object(x) => x
+                .iter()
+                .map(|(k, v)| (Value::String(k.clone()), v.clone()))
+                .collect(),
+            Value::Array(x) => x
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (Value::from(i), v.clone()))
+                .collect(),
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<Vec<(Value, Value)>>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Option<Vec<(Value, Value)>> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::Null => None,
+            Value::Object(x) => Some(
+                x.iter()
+                    .map(|(k, v)| (Value::String(k.clone()), v.clone()))
+                    .collect(),
+            ),
+            Value::Array(x) => Some(
+                x.iter()
+                    .enumerate()
+                    .map(|(i, v)| (Value::from(i), v.clone()))
+                    .collect(),
+            ),
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<Decimal>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Option<Decimal> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::Null => None,
+            Value::String(x) => Some(Decimal::from_str_exact(x).map_err(|_| {
+                anyhow!(
+                    "Trino cannot parse Decimal at position: ({}, {}): {:?}",
+                    ridx,
+                    cidx,
+                    value
+                )
+            })?),
+            _ => throw!(anyhow!(
+                "Trino unknown value at position: ({}, {}): {:?}",
+                ridx,
+                cidx,
+                value
+            )),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::convert::TryFrom;
+
+use chrono::{DateTime, FixedOffset};
+use fehler::{throw, throws};
+use prusto::{PrestoTy, PrimitiveType};
+use serde_json::Value;
+
+use crate::impl_typesystem;
+
+use super::errors::TrinoSourceError;
+
+// TIME WITH TIME ZONE has no date component, so it can't reuse
+// `DateTime<FixedOffset>` - that native type is already claimed by
+// `TimestampTz` in the `impl_typesystem!` mapping below, and the two need
+// different parsing (a bare time-with-zone string vs a full timestamp).
+// This newtype gives TIME WITH TIME ZONE its own slot while still
+// preserving the parsed offset, synthesized onto the Unix epoch day.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TrinoTimeTz(pub DateTime<FixedOffset>);
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TrinoTypeSystem {
+    Boolean(bool),
+    TinyInt(bool),
+    SmallInt(bool),
+    Integer(bool),
+    BigInt(bool),
+    Real(bool),
+    Double(bool),
+    Date(bool),
+    Time(bool),
+    // TIME WITH TIME ZONE: keeps the parsed offset, see `TrinoTimeTz`.
+    TimeTz(bool),
+    Timestamp(bool),
+    // TIMESTAMP WITH TIME ZONE: keeps the original offset.
+    TimestampTz(bool),
+    // DECIMAL(p, s): Trino hands these back as strings to avoid precision
+    // loss. `impl_typesystem!` matches every variant below as a single-field
+    // `(nullable)` tuple, so the declared precision/scale can't ride in the
+    // variant itself - they're carried out-of-band in
+    // `TrinoSource::decimal_precision`, keyed by column name and populated
+    // by `fetch_metadata`, so the destination can still size a fixed-point
+    // column without re-deriving them from a sampled value. Note that
+    // `rust_decimal::Decimal` tops out at ~28 significant digits, so a value
+    // of a `DECIMAL(38, s)` column with more digits than that fails to parse
+    // even though it round-trips through Trino as a string.
+    Decimal(bool),
+    Varchar(bool),
+    Char(bool),
+    // ARRAY(t): elements stay as raw JSON values, the destination decides
+    // whether/how to further type them.
+    Array(bool),
+    // MAP(k, v) / ROW(...): both surface as key/value pairs of raw JSON
+    // values (a ROW's "key" is its field's position).
+    Map(bool),
+    Row(bool),
+    // JSON: Trino already hands this back as a JSON-encoded string.
+    Json(bool),
+}
+
+impl_typesystem! {
+    system = TrinoTypeSystem,
+    mappings = {
+        { Boolean => bool }
+        { TinyInt => i8 }
+        { SmallInt => i16 }
+        { Integer => i32 }
+        { BigInt => i64 }
+        { Real => f32 }
+        { Double => f64 }
+        { Date => chrono::NaiveDate }
+        { Time => chrono::NaiveTime }
+        { TimeTz => TrinoTimeTz }
+        { Timestamp => chrono::NaiveDateTime }
+        { TimestampTz => DateTime<FixedOffset> }
+        { Decimal => rust_decimal::Decimal }
+        { Varchar => String }
+        { Char => char }
+        { Array => Vec<Value> }
+        { Map => Vec<(Value, Value)> }
+        { Row => Vec<(Value, Value)> }
+        { Json => String }
+    }
+}
+
+impl TryFrom<PrestoTy> for TrinoTypeSystem {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn try_from(ty: PrestoTy) -> TrinoTypeSystem {
+        match ty {
+            PrestoTy::PrimitiveType(p) => match p {
+                PrimitiveType::Boolean => TrinoTypeSystem::Boolean(true),
+                PrimitiveType::TinyInt => TrinoTypeSystem::TinyInt(true),
+                PrimitiveType::SmallInt => TrinoTypeSystem::SmallInt(true),
+                PrimitiveType::Integer => TrinoTypeSystem::Integer(true),
+                PrimitiveType::BigInt => TrinoTypeSystem::BigInt(true),
+                PrimitiveType::Real => TrinoTypeSystem::Real(true),
+                PrimitiveType::Double => TrinoTypeSystem::Double(true),
+                PrimitiveType::Date => TrinoTypeSystem::Date(true),
+                PrimitiveType::Time(_) => TrinoTypeSystem::Time(true),
+                PrimitiveType::TimeWithTimeZone(_) => TrinoTypeSystem::TimeTz(true),
+                PrimitiveType::Timestamp(_) => TrinoTypeSystem::Timestamp(true),
+                PrimitiveType::TimestampWithTimeZone(_) => TrinoTypeSystem::TimestampTz(true),
+                PrimitiveType::Varchar(_) => TrinoTypeSystem::Varchar(true),
+                PrimitiveType::Char(_) => TrinoTypeSystem::Char(true),
+                PrimitiveType::Json => TrinoTypeSystem::Json(true),
+                _ => throw!(anyhow::anyhow!("Trino type {:?} is not supported yet", p)),
+            },
+            PrestoTy::Decimal(_precision, _scale) => TrinoTypeSystem::Decimal(true),
+            PrestoTy::Array(_) => TrinoTypeSystem::Array(true),
+            PrestoTy::Map(_, _) => TrinoTypeSystem::Map(true),
+            PrestoTy::Row(_) => TrinoTypeSystem::Row(true),
+            _ => throw!(anyhow::anyhow!("Trino type {:?} is not supported yet", ty)),
+        }
+    }
+}